@@ -3,9 +3,10 @@ use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
 use nu_protocol::{
     dataframe::{Column, NuDataFrame},
-    Signature, SyntaxShape, UntaggedValue, Value,
+    Primitive, Signature, SyntaxShape, UntaggedValue, Value,
 };
-use polars::prelude::DataType;
+use num_traits::ToPrimitive;
+use polars::prelude::{DataType, UInt32Chunked};
 
 use super::utils::parse_polars_error;
 
@@ -17,14 +18,14 @@ impl WholeStreamCommand for DataFrame {
     }
 
     fn usage(&self) -> &str {
-        "[DataFrame, Series] Creates new dataframe using the given indices"
+        "[DataFrame, Series] Creates new dataframe using the given indices. Accepts a series/dataframe of indices or a plain list of integers, and allows negative indices to count back from the last row"
     }
 
     fn signature(&self) -> Signature {
         Signature::build("dataframe take").required(
             "indices",
             SyntaxShape::Any,
-            "list of indices used to take data",
+            "list of indices used to take data. Negative indices count back from the last row",
         )
     }
 
@@ -70,65 +71,155 @@ impl WholeStreamCommand for DataFrame {
                 .expect("simple df for test should not fail")
                 .into_value(Tag::default())]),
             },
+            Example {
+                description: "Takes selected rows using a list literal and a negative index",
+                example: r#"let df = ([[a b]; [4 1] [5 2] [4 3]] | dataframe to-df);
+    $df | dataframe take [0 -1]"#,
+                result: Some(vec![NuDataFrame::try_from_columns(
+                    vec![
+                        Column::new(
+                            "a".to_string(),
+                            vec![UntaggedValue::int(4).into(), UntaggedValue::int(4).into()],
+                        ),
+                        Column::new(
+                            "b".to_string(),
+                            vec![UntaggedValue::int(1).into(), UntaggedValue::int(3).into()],
+                        ),
+                    ],
+                    &Span::default(),
+                )
+                .expect("simple df for test should not fail")
+                .into_value(Tag::default())]),
+            },
+            Example {
+                description: "An empty list of indices returns an empty dataframe with the same schema",
+                example: r#"let df = ([[a b]; [4 1] [5 2] [4 3]] | dataframe to-df);
+    $df | dataframe take []"#,
+                result: Some(vec![NuDataFrame::try_from_columns(
+                    vec![Column::new("a".to_string(), vec![]), Column::new("b".to_string(), vec![])],
+                    &Span::default(),
+                )
+                .expect("simple df for test should not fail")
+                .into_value(Tag::default())]),
+            },
         ]
     }
 }
 
 fn command(mut args: CommandArgs) -> Result<OutputStream, ShellError> {
     let tag = args.call_info.name_tag.clone();
-    let value: Value = args.req(0)?;
-
-    let df = match &value.value {
-        UntaggedValue::DataFrame(df) => Ok(df),
-        _ => Err(ShellError::labeled_error(
-            "Incorrect type",
-            "can only use a series for take command",
-            value.tag.span,
-        )),
-    }?;
-
-    let series = df.as_series(&value.tag.span)?;
-
-    let casted = match series.dtype() {
-        DataType::UInt32 | DataType::UInt64 | DataType::Int32 | DataType::Int64 => series
-            .as_ref()
-            .cast_with_dtype(&DataType::UInt32)
-            .map_err(|e| parse_polars_error::<&str>(&e, &value.tag.span, None)),
-        _ => Err(ShellError::labeled_error_with_secondary(
-            "Incorrect type",
-            "Series with incorrect type",
-            &value.tag.span,
-            "Consider using a Series with type int type",
-            &value.tag.span,
-        )),
-    }?;
-
-    let indices = casted
-        .u32()
-        .map_err(|e| parse_polars_error::<&str>(&e, &value.tag.span, None))?;
+    let index_value: Value = args.req(0)?;
 
     let value = args.input.next().ok_or_else(|| {
         ShellError::labeled_error("Empty stream", "No value found in the stream", &tag)
     })?;
 
-    match value.value {
-        UntaggedValue::DataFrame(df) => {
-            let res = df.as_ref().take(indices);
-
-            Ok(OutputStream::one(NuDataFrame::dataframe_to_value(res, tag)))
+    let df = match &value.value {
+        UntaggedValue::DataFrame(df) => df,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "No dataframe or series in stream",
+                "no dataframe or series found in input stream",
+                &value.tag.span,
+            ))
         }
-        _ => Err(ShellError::labeled_error(
-            "No dataframe or series in stream",
-            "no dataframe or series found in input stream",
-            &value.tag.span,
-        )),
-    }
+    };
+
+    let height = df.as_ref().height() as i64;
+
+    let indices = match &index_value.value {
+        UntaggedValue::DataFrame(indices_df) => {
+            let series = indices_df.as_series(&index_value.tag.span)?;
+
+            let casted = match series.dtype() {
+                DataType::UInt32 | DataType::UInt64 | DataType::Int32 | DataType::Int64 => series
+                    .as_ref()
+                    .cast_with_dtype(&DataType::Int64)
+                    .map_err(|e| parse_polars_error::<&str>(&e, &index_value.tag.span, None)),
+                _ => Err(ShellError::labeled_error_with_secondary(
+                    "Incorrect type",
+                    "Series with incorrect type",
+                    &index_value.tag.span,
+                    "Consider using a Series with type int type",
+                    &index_value.tag.span,
+                )),
+            }?;
+
+            let raw = casted
+                .i64()
+                .map_err(|e| parse_polars_error::<&str>(&e, &index_value.tag.span, None))?
+                .into_no_null_iter()
+                .collect::<Vec<i64>>();
+
+            resolve_indices(&raw, height, &index_value.tag.span)?
+        }
+        UntaggedValue::Table(list) => {
+            let raw = list
+                .iter()
+                .map(|value| match &value.value {
+                    UntaggedValue::Primitive(Primitive::Int(n)) => n.to_i64().ok_or_else(|| {
+                        ShellError::labeled_error(
+                            "Incorrect value",
+                            "index does not fit in a 64-bit integer",
+                            &value.tag.span,
+                        )
+                    }),
+                    _ => Err(ShellError::labeled_error(
+                        "Incorrect value",
+                        "expected an integer index",
+                        &value.tag.span,
+                    )),
+                })
+                .collect::<Result<Vec<i64>, ShellError>>()?;
+
+            resolve_indices(&raw, height, &index_value.tag.span)?
+        }
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Incorrect type",
+                "indices must be a series, dataframe or list of integers",
+                &index_value.tag.span,
+            ))
+        }
+    };
+
+    let indices = UInt32Chunked::new_from_slice("idx", &indices);
+    let res = df.as_ref().take(&indices);
+
+    Ok(OutputStream::one(NuDataFrame::dataframe_to_value(res, tag)))
+}
+
+// Resolves a list of possibly negative indices against a dataframe of the
+// given height, wrapping negative indices once (idx + height), and returns
+// a labeled error naming the offending index if it is still out of range
+// after wrapping.
+fn resolve_indices(raw: &[i64], height: i64, span: &Span) -> Result<Vec<u32>, ShellError> {
+    raw.iter()
+        .map(|&idx| {
+            let resolved = if idx < 0 { idx + height } else { idx };
+
+            if resolved < 0 || resolved >= height {
+                Err(ShellError::labeled_error(
+                    "Index out of bounds",
+                    format!(
+                        "index {} is out of bounds for a dataframe with {} rows",
+                        idx, height
+                    ),
+                    span,
+                ))
+            } else {
+                Ok(resolved as u32)
+            }
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
+    use super::resolve_indices;
     use super::DataFrame;
     use super::ShellError;
+    use crate::prelude::Span;
 
     #[test]
     fn examples_work_as_expected() -> Result<(), ShellError> {
@@ -136,4 +227,18 @@ mod tests {
 
         test_examples(DataFrame {})
     }
+
+    #[test]
+    fn out_of_range_index_errors_out() {
+        let span = Span::default();
+
+        // positive index past the end of the frame
+        assert!(resolve_indices(&[5], 3, &span).is_err());
+
+        // negative index still out of range after wrapping
+        assert!(resolve_indices(&[-4], 3, &span).is_err());
+
+        // a very negative index is still just out of range, not an overflow
+        assert!(resolve_indices(&[i64::MIN], 3, &span).is_err());
+    }
 }
@@ -0,0 +1,116 @@
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Primitive, ReturnSuccess, UntaggedValue, Value};
+use nu_source::Tag;
+
+use digest::Digest;
+use hmac::{Hmac, Mac, NewMac};
+
+/// Output encoding for a digest or HMAC, selected with `--encoding`.
+pub enum Encoding {
+    Hex,
+    Base64,
+}
+
+impl std::str::FromStr for Encoding {
+    type Err = ShellError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "hex" => Ok(Encoding::Hex),
+            "base64" => Ok(Encoding::Base64),
+            _ => Err(ShellError::untagged_runtime_error(format!(
+                "'{}' is not a valid encoding, expected 'hex' or 'base64'",
+                s
+            ))),
+        }
+    }
+}
+
+pub fn run<D>(args: CommandArgs) -> Result<OutputStream, ShellError>
+where
+    D: Digest + Clone + digest::BlockInput + digest::FixedOutput + digest::Reset + Default,
+{
+    let column_paths: Vec<ColumnPath> = args.rest(0)?;
+    let key: Option<Tagged<String>> = args.get_flag("hmac")?;
+    let encoding: Option<Tagged<String>> = args.get_flag("encoding")?;
+
+    let encoding = match encoding {
+        Some(tagged) => tagged
+            .item
+            .parse::<Encoding>()
+            .map_err(|e| ShellError::labeled_error("invalid encoding", e.to_string(), &tagged.tag))?,
+        None => Encoding::Hex,
+    };
+    let key = key.map(|tagged| tagged.item);
+
+    let eval = move |value: Value| -> Result<Value, ShellError> {
+        if column_paths.is_empty() {
+            action::<D>(&value, value.tag(), key.as_deref(), &encoding)
+        } else {
+            let mut ret = value;
+            for path in &column_paths {
+                let key = key.clone();
+                let encoding = &encoding;
+                ret = ret.swap_data_by_column_path(
+                    path,
+                    Box::new(move |old| action::<D>(old, old.tag(), key.as_deref(), encoding)),
+                )?;
+            }
+            Ok(ret)
+        }
+    };
+
+    Ok(args
+        .input
+        .map(move |v| ReturnSuccess::value(eval(v)?))
+        .to_output_stream())
+}
+
+pub fn action<D>(
+    input: &Value,
+    tag: impl Into<Tag>,
+    key: Option<&str>,
+    encoding: &Encoding,
+) -> Result<Value, ShellError>
+where
+    D: Digest + Clone + digest::BlockInput + digest::FixedOutput + digest::Reset + Default,
+{
+    let tag = tag.into();
+
+    let bytes = match &input.value {
+        UntaggedValue::Primitive(Primitive::Binary(binary_value)) => binary_value.clone(),
+        UntaggedValue::Primitive(Primitive::String(string_value)) => {
+            string_value.as_bytes().to_vec()
+        }
+        other => {
+            let type_name = other.type_name();
+            return Err(ShellError::type_error(
+                "string or binary",
+                type_name.spanned(tag.span),
+            ));
+        }
+    };
+
+    let digest = match key {
+        Some(key) => {
+            let mut mac = Hmac::<D>::new_from_slice(key.as_bytes()).map_err(|_| {
+                ShellError::labeled_error(
+                    "Invalid HMAC key",
+                    "HMAC key could not be used to initialize the digest",
+                    &tag,
+                )
+            })?;
+            mac.update(&bytes);
+            mac.finalize().into_bytes().to_vec()
+        }
+        None => D::digest(&bytes).to_vec(),
+    };
+
+    let encoded = match encoding {
+        Encoding::Hex => hex::encode(digest),
+        Encoding::Base64 => base64::encode(digest),
+    };
+
+    Ok(UntaggedValue::string(encoded).into_value(tag))
+}
@@ -14,10 +14,23 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("hash md5").rest(
-            SyntaxShape::ColumnPath,
-            "optionally md5 encode data by column paths",
-        )
+        Signature::build("hash md5")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally md5 encode data by column paths",
+            )
+            .named(
+                "hmac",
+                SyntaxShape::String,
+                "compute a keyed-hash (HMAC) using the given key instead of a bare digest",
+                Some('k'),
+            )
+            .named(
+                "encoding",
+                SyntaxShape::String,
+                "output encoding: 'hex' (default) or 'base64'",
+                Some('e'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -46,6 +59,20 @@ impl WholeStreamCommand for SubCommand {
                 )
                 .into_untagged_value()]),
             },
+            Example {
+                description: "md5 encode a string with a HMAC key",
+                example: "echo 'abcdefghijklmnopqrstuvwxyz' | hash md5 --hmac mykey",
+                result: Some(vec![UntaggedValue::string(
+                    "d2b83a6deb60aef35ca7e5804938774a",
+                )
+                .into_untagged_value()]),
+            },
+            Example {
+                description: "md5 encode a string and output as base64",
+                example: "echo 'abcdefghijklmnopqrstuvwxyz' | hash md5 --encoding base64",
+                result: Some(vec![UntaggedValue::string("w/zT12GS5AB9+0lsymfhOw==")
+                    .into_untagged_value()]),
+            },
         ]
     }
 }
@@ -57,7 +84,7 @@ mod tests {
     use nu_source::Tag;
     use nu_test_support::value::string;
 
-    use crate::commands::generators::hash_::generic_digest::action;
+    use crate::commands::generators::hash_::generic_digest::{action, Encoding};
 
     #[test]
     fn md5_encode_string() {
@@ -65,7 +92,7 @@ mod tests {
         let expected =
             UntaggedValue::string("c3fcd3d76192e4007dfb496cca67e13b").into_untagged_value();
 
-        let actual = action::<Md5>(&word, Tag::unknown()).unwrap();
+        let actual = action::<Md5>(&word, Tag::unknown(), None, &Encoding::Hex).unwrap();
         assert_eq!(actual, expected);
     }
 
@@ -76,7 +103,27 @@ mod tests {
         let expected =
             UntaggedValue::string("5f80e231382769b0102b1164cf722d83").into_untagged_value();
 
-        let actual = action::<Md5>(&binary, Tag::unknown()).unwrap();
+        let actual = action::<Md5>(&binary, Tag::unknown(), None, &Encoding::Hex).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn md5_encode_string_keyed_hmac() {
+        let word = string("abcdefghijklmnopqrstuvwxyz");
+        let expected =
+            UntaggedValue::string("d2b83a6deb60aef35ca7e5804938774a").into_untagged_value();
+
+        let actual = action::<Md5>(&word, Tag::unknown(), Some("mykey"), &Encoding::Hex).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn md5_encode_string_base64() {
+        let word = string("abcdefghijklmnopqrstuvwxyz");
+        let expected =
+            UntaggedValue::string("w/zT12GS5AB9+0lsymfhOw==").into_untagged_value();
+
+        let actual = action::<Md5>(&word, Tag::unknown(), None, &Encoding::Base64).unwrap();
         assert_eq!(actual, expected);
     }
 }